@@ -1,17 +1,23 @@
 use std::sync::Mutex;
 use tauri::webview::WebviewWindowBuilder;
+use tauri::Emitter;
 use tauri::Manager;
 use tauri::WebviewUrl;
 use tauri_plugin_shell::process::CommandEvent;
 use tauri_plugin_shell::ShellExt;
 
-const FLASK_PORT: u16 = 8000;
+/// Dev builds run Flask separately (outside Rust's control, e.g. `flask run`),
+/// so there's no handshake to read its port from — it's expected on this
+/// fixed port by the dev workflow. Production instead binds an ephemeral port
+/// at runtime; see `pick_free_port`.
+#[cfg(dev)]
+const DEV_FLASK_PORT: u16 = 8000;
 
 // ── Windows Job Object ────────────────────────────────────────────────────────
 //
 // On Windows, killing a PyInstaller --onefile process only kills the
 // bootstrapper (parent). The real Python child process becomes an orphan and
-// keeps running (holding port 8000) even after the Tauri app exits.
+// keeps running (holding its port) even after the Tauri app exits.
 //
 // A Windows Job Object with JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE solves this
 // at the OS level: when the last handle to the Job is closed (i.e. when the
@@ -31,16 +37,24 @@ const FLASK_PORT: u16 = 8000;
 mod job {
     use windows_sys::Win32::Foundation::{CloseHandle, HANDLE, INVALID_HANDLE_VALUE};
     use windows_sys::Win32::Security::SECURITY_ATTRIBUTES;
+    use windows_sys::Win32::System::Diagnostics::Debug::{SetErrorMode, SEM_NOGPFAULTERRORBOX};
     use windows_sys::Win32::System::JobObjects::{
         AssignProcessToJobObject, CreateJobObjectW, JobObjectExtendedLimitInformation,
         SetInformationJobObject, JOBOBJECT_EXTENDED_LIMIT_INFORMATION,
-        JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE,
+        JOB_OBJECT_LIMIT_JOB_MEMORY, JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE,
+        JOB_OBJECT_LIMIT_PRIORITY_CLASS,
     };
     use windows_sys::Win32::System::Threading::OpenProcess;
-    use windows_sys::Win32::System::Threading::{PROCESS_SET_QUOTA, PROCESS_TERMINATE};
+    use windows_sys::Win32::System::Threading::{
+        BELOW_NORMAL_PRIORITY_CLASS, PROCESS_SET_QUOTA, PROCESS_TERMINATE,
+    };
 
     use std::sync::OnceLock;
 
+    /// Cap the sidecar's committed memory so a runaway PDF job can't take down
+    /// the whole machine. Generous enough for normal rendering workloads.
+    const SIDECAR_MEMORY_LIMIT_BYTES: usize = 1024 * 1024 * 1024;
+
     /// Global Job Object handle. Kept open for the Tauri process lifetime.
     /// When this handle is closed (process exit), Windows kills all job members.
     static JOB_HANDLE: OnceLock<JobHandle> = OnceLock::new();
@@ -62,9 +76,19 @@ mod job {
         }
     }
 
-    /// Create the global Job Object with KILL_ON_JOB_CLOSE.
-    /// Must be called once at startup, before spawning the sidecar.
+    /// Create the global Job Object with KILL_ON_JOB_CLOSE, a below-normal
+    /// priority class so heavy PDF/Python work doesn't starve the UI thread,
+    /// and a committed-memory cap. Must be called once at startup, before
+    /// spawning the sidecar.
     pub fn create_job() {
+        // Suppress the "this program has stopped working" GP-fault dialog a
+        // crashing PyInstaller child would otherwise pop up — it's modal and
+        // would block our own teardown (and the crash supervisor's restart)
+        // until someone clicks through it.
+        unsafe {
+            SetErrorMode(SEM_NOGPFAULTERRORBOX);
+        }
+
         unsafe {
             let job = CreateJobObjectW(std::ptr::null::<SECURITY_ATTRIBUTES>(), std::ptr::null());
             if job.is_null() {
@@ -72,9 +96,12 @@ mod job {
                 return;
             }
 
-            // Set KILL_ON_JOB_CLOSE so all members die when our handle closes.
             let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = std::mem::zeroed();
-            info.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE;
+            info.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE
+                | JOB_OBJECT_LIMIT_PRIORITY_CLASS
+                | JOB_OBJECT_LIMIT_JOB_MEMORY;
+            info.BasicLimitInformation.PriorityClass = BELOW_NORMAL_PRIORITY_CLASS;
+            info.JobMemoryLimit = SIDECAR_MEMORY_LIMIT_BYTES;
 
             let ok = SetInformationJobObject(
                 job,
@@ -91,7 +118,10 @@ mod job {
 
             // Store handle globally — never manually closed, Windows does it on exit.
             let _ = JOB_HANDLE.set(JobHandle(job));
-            println!("[tauri] Job Object created (KILL_ON_JOB_CLOSE)");
+            println!(
+                "[tauri] Job Object created (KILL_ON_JOB_CLOSE, BELOW_NORMAL_PRIORITY_CLASS, {}MB memory cap)",
+                SIDECAR_MEMORY_LIMIT_BYTES / (1024 * 1024)
+            );
         }
     }
 
@@ -123,42 +153,150 @@ mod job {
 
 // ── Sidecar state ─────────────────────────────────────────────────────────────
 
-/// Holds the sidecar process handle and its PID for explicit cleanup on graceful exit.
-/// The Job Object is the primary safety net on Windows; this is belt-and-suspenders.
-struct Sidecar {
-    child: Mutex<Option<tauri_plugin_shell::process::CommandChild>>,
+/// A single spawned sidecar process and the platform handle needed to kill it.
+struct SidecarProcess {
+    child: tauri_plugin_shell::process::CommandChild,
     /// PID of the spawned sidecar process, used for scoped tree-kill on Windows.
     #[cfg(windows)]
     pid: u32,
+    /// Process group id of the sidecar. The sidecar is made the leader of its
+    /// own group immediately after spawn (see `spawn_and_watch`), so this is
+    /// also its own PID. Killing the whole group reaps the PyInstaller
+    /// bootstrapper and its unpacked Python child together, regardless of
+    /// which port (if any) they end up bound to.
+    #[cfg(unix)]
+    pgid: libc::pid_t,
 }
 
-impl Sidecar {
+impl SidecarProcess {
     fn new(child: tauri_plugin_shell::process::CommandChild) -> Self {
         Self {
             #[cfg(windows)]
             pid: child.pid(),
-            child: Mutex::new(Some(child)),
+            #[cfg(unix)]
+            pgid: child.pid() as libc::pid_t,
+            child,
         }
     }
+}
 
-    /// Explicit kill: send kill signal + platform-specific tree kill.
-    fn kill(&self) {
-        if let Ok(mut guard) = self.child.lock() {
-            if let Some(child) = guard.take() {
-                println!("[tauri] Sending kill to sidecar...");
-                let _ = child.kill();
-            }
+/// Holds the current sidecar process for explicit cleanup on graceful exit,
+/// plus the flag the crash supervisor uses to tell an intentional shutdown
+/// apart from an unexpected crash. The Job Object is the primary safety net
+/// on Windows; this is belt-and-suspenders.
+///
+/// The process is replaced in place (see `replace`) whenever the supervisor
+/// respawns a crashed sidecar, so this handle stays valid for the lifetime of
+/// the app rather than being re-created per process.
+struct Sidecar {
+    process: Mutex<Option<SidecarProcess>>,
+    /// Set just before an intentional kill so the crash supervisor doesn't
+    /// mistake the resulting `CommandEvent::Terminated` for a crash.
+    shutting_down: std::sync::atomic::AtomicBool,
+    /// Ephemeral port picked for this sidecar's lifetime (see `pick_free_port`).
+    /// Fixed at creation and reused by the supervisor across crash restarts —
+    /// only the process, not the port, changes on respawn.
+    port: u16,
+    /// Consecutive crash-restart count for the *current* incident. Reset to 0
+    /// by `health_check_and_navigate` once a generation reports healthy, so
+    /// `MAX_RESTART_ATTEMPTS` bounds a single string of back-to-back crashes
+    /// rather than the sidecar's total crash count since launch.
+    attempt: std::sync::atomic::AtomicU32,
+}
+
+impl Sidecar {
+    fn new(child: tauri_plugin_shell::process::CommandChild, port: u16) -> Self {
+        Self {
+            process: Mutex::new(Some(SidecarProcess::new(child))),
+            shutting_down: std::sync::atomic::AtomicBool::new(false),
+            port,
+            attempt: std::sync::atomic::AtomicU32::new(0),
         }
-        // Windows: kill the process tree rooted at the sidecar's PID.
-        // This handles the PyInstaller parent+child situation explicitly
-        // and complements the Job Object for graceful exit paths.
-        // Using /PID instead of /IM to avoid killing unrelated instances.
+    }
+
+    /// Swap in a freshly spawned process after the supervisor respawns a crashed sidecar.
+    fn replace(&self, child: tauri_plugin_shell::process::CommandChild) {
+        if let Ok(mut guard) = self.process.lock() {
+            *guard = Some(SidecarProcess::new(child));
+        }
+    }
+
+    fn is_shutting_down(&self) -> bool {
+        self.shutting_down.load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    fn port(&self) -> u16 {
+        self.port
+    }
+
+    fn attempt(&self) -> u32 {
+        self.attempt.load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    fn set_attempt(&self, attempt: u32) {
+        self.attempt.store(attempt, std::sync::atomic::Ordering::SeqCst);
+    }
+
+    /// Called once a respawned generation's health check succeeds, so the
+    /// next crash (if any) gets the full restart budget again instead of
+    /// picking up where a long-resolved incident left off.
+    fn reset_attempt(&self) {
+        self.set_attempt(0);
+    }
+
+    /// Explicit kill: ask the sidecar to shut down gracefully, then escalate
+    /// to a hard kill if it doesn't exit within `GRACEFUL_SHUTDOWN_TIMEOUT`.
+    /// Giving Flask a chance to finish an in-flight PDF write or release a
+    /// file lock avoids corrupted output on a normal app close; the Job
+    /// Object (Windows) remains the safety net for crashes and force-kills.
+    /// On Unix, a group still alive after the timeout gets SIGKILL; nothing
+    /// reaps the group if the Tauri process itself crashes.
+    fn kill(&self) {
+        self.shutting_down
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+        self.kill_current_process();
+    }
+
+    /// Tear down whatever's left of the current process, if it's still
+    /// around, without leaving `shutting_down` set — used by the manual
+    /// "retry" command, where a brand-new process is about to be spawned and
+    /// its own future crashes should still reach the crash supervisor.
+    fn kill_for_restart(&self) {
+        self.kill_current_process();
+    }
+
+    fn kill_current_process(&self) {
+        let Some(proc) = self.process.lock().ok().and_then(|mut guard| guard.take()) else {
+            return;
+        };
+
         #[cfg(windows)]
-        Self::kill_process_tree_windows(self.pid);
+        Self::graceful_kill_windows(proc.pid);
 
-        // Unix: kill by port (lsof).
         #[cfg(unix)]
-        Self::kill_port_unix();
+        Self::graceful_kill_unix(proc.pgid);
+    }
+
+    /// Windows: ask nicely first (`taskkill` without `/F` posts WM_CLOSE to the
+    /// tree's windows and lets well-behaved processes exit on their own), poll
+    /// for exit, then fall back to a forced tree-kill.
+    #[cfg(windows)]
+    fn graceful_kill_windows(pid: u32) {
+        println!("[tauri] Requesting graceful shutdown of sidecar PID {}...", pid);
+        let _ = std::process::Command::new("taskkill")
+            .args(["/PID", &pid.to_string(), "/T"])
+            .output();
+
+        if Self::wait_for_exit_windows(pid, GRACEFUL_SHUTDOWN_TIMEOUT) {
+            println!("[tauri] Sidecar exited gracefully");
+            return;
+        }
+
+        println!(
+            "[tauri] Sidecar did not exit within {:?}, forcing shutdown",
+            GRACEFUL_SHUTDOWN_TIMEOUT
+        );
+        Self::kill_process_tree_windows(pid);
     }
 
     /// Windows: taskkill /F /PID <pid> /T — kills the tree rooted at the given PID only.
@@ -169,40 +307,191 @@ impl Sidecar {
             .output();
     }
 
-    /// Unix: kill by port via lsof.
+    /// Windows: poll `GetExitCodeProcess` until the PID exits or `timeout` elapses.
+    #[cfg(windows)]
+    fn wait_for_exit_windows(pid: u32, timeout: std::time::Duration) -> bool {
+        let deadline = std::time::Instant::now() + timeout;
+        while std::time::Instant::now() < deadline {
+            if !Self::process_alive_windows(pid) {
+                return true;
+            }
+            std::thread::sleep(GRACEFUL_POLL_INTERVAL);
+        }
+        false
+    }
+
+    #[cfg(windows)]
+    fn process_alive_windows(pid: u32) -> bool {
+        use windows_sys::Win32::Foundation::CloseHandle;
+        use windows_sys::Win32::System::Threading::{
+            GetExitCodeProcess, OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION,
+        };
+        const STILL_ACTIVE: u32 = 259;
+
+        unsafe {
+            let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, pid);
+            if handle.is_null() {
+                return false;
+            }
+            let mut exit_code: u32 = 0;
+            let ok = GetExitCodeProcess(handle, &mut exit_code);
+            CloseHandle(handle);
+            ok != 0 && exit_code == STILL_ACTIVE
+        }
+    }
+
+    /// Unix: send SIGTERM to the whole process group, poll for exit, then
+    /// escalate to SIGKILL if the group is still alive after the timeout.
     #[cfg(unix)]
-    fn kill_port_unix() {
-        let _ = std::process::Command::new("sh")
-            .args([
-                "-c",
-                &format!("lsof -ti :{} | xargs kill -9 2>/dev/null", FLASK_PORT),
-            ])
-            .output();
+    fn graceful_kill_unix(pgid: libc::pid_t) {
+        println!("[tauri] Sending SIGTERM to sidecar process group {}...", pgid);
+        unsafe {
+            libc::killpg(pgid, libc::SIGTERM);
+        }
+
+        if Self::wait_for_exit_unix(pgid, GRACEFUL_SHUTDOWN_TIMEOUT) {
+            println!("[tauri] Sidecar exited gracefully");
+            return;
+        }
+
+        println!(
+            "[tauri] Sidecar did not exit within {:?}, sending SIGKILL",
+            GRACEFUL_SHUTDOWN_TIMEOUT
+        );
+        Self::kill_process_group_unix(pgid);
+    }
+
+    /// Unix: `killpg(pgid, SIGKILL)` — kills every process in the sidecar's group.
+    #[cfg(unix)]
+    fn kill_process_group_unix(pgid: libc::pid_t) {
+        unsafe {
+            libc::killpg(pgid, libc::SIGKILL);
+        }
+    }
+
+    /// Unix: poll the process group for liveness until it's empty or `timeout` elapses.
+    /// `kill(-pgid, 0)` delivers no signal but fails with ESRCH once no process in
+    /// the group remains, which is the standard liveness-check idiom.
+    #[cfg(unix)]
+    fn wait_for_exit_unix(pgid: libc::pid_t, timeout: std::time::Duration) -> bool {
+        let deadline = std::time::Instant::now() + timeout;
+        while std::time::Instant::now() < deadline {
+            let alive = unsafe { libc::kill(-pgid, 0) == 0 };
+            if !alive {
+                return true;
+            }
+            std::thread::sleep(GRACEFUL_POLL_INTERVAL);
+        }
+        false
     }
 }
 
+/// How long to wait for the sidecar to exit on its own after a graceful
+/// shutdown request before escalating to a hard kill.
+const GRACEFUL_SHUTDOWN_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(3);
+/// Poll interval while waiting for graceful exit.
+const GRACEFUL_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100);
+
 // ── Python backend launcher ───────────────────────────────────────────────────
 
-/// Spawn the Python backend sidecar, assign it to the Job Object (Windows),
-/// and read its output in background.
-fn start_python_backend(app: &tauri::AppHandle) -> tauri_plugin_shell::process::CommandChild {
+/// Maximum number of consecutive crash-restart attempts before giving up and
+/// showing the error page instead of a stale/dead webview.
+const MAX_RESTART_ATTEMPTS: u32 = 5;
+/// Base delay for the crash-restart exponential backoff (500ms, 1s, 2s, ...).
+const RESTART_BACKOFF_BASE: std::time::Duration = std::time::Duration::from_millis(500);
+/// Upper bound on the crash-restart backoff delay.
+const RESTART_BACKOFF_MAX: std::time::Duration = std::time::Duration::from_secs(8);
+
+/// Reports whether the currently-spawned sidecar process has exited, set by
+/// whichever death-detection path notices first: the Linux pidfd watcher
+/// (near-instant) or `CommandEvent::Terminated` (all platforms, fallback).
+/// A `watch` channel (rather than e.g. `Notify`) is used deliberately: its
+/// value persists once set, so `health_check_and_navigate` can't miss the
+/// signal by not happening to be awaiting it at the exact moment it fires.
+type DeathSignal = tokio::sync::watch::Receiver<bool>;
+
+/// Bind an ephemeral port, read what the OS assigned, then release it so the
+/// sidecar can bind it in turn. Removes the fixed-port collision failure mode
+/// entirely: a second instance, or any unrelated service already sitting on a
+/// hardcoded port, can no longer block startup, and — combined with
+/// process-group cleanup — there's no longer a port to kill by either.
+fn pick_free_port() -> u16 {
+    std::net::TcpListener::bind("127.0.0.1:0")
+        .expect("failed to bind an ephemeral port")
+        .local_addr()
+        .expect("failed to read the bound ephemeral port")
+        .port()
+}
+
+/// Spawn the Python backend sidecar on `port`, assign it to the Job Object
+/// (Windows), register it as (or swap it into) the managed `Sidecar` state,
+/// and watch its output in the background. If it terminates unexpectedly —
+/// i.e. not as part of an app shutdown — hands off to
+/// `handle_unexpected_exit` to respawn it on the same port.
+/// Returns the `DeathSignal` for this process generation, for
+/// `health_check_and_navigate` to watch.
+fn spawn_and_watch(app: tauri::AppHandle, attempt: u32, port: u16) -> DeathSignal {
+    let _ = app.emit("backend://starting", ());
+
     let (mut rx, child) = app
         .shell()
         .sidecar("doctorfill-server")
         .expect("failed to create doctorfill-server sidecar")
+        .env("FLASK_PORT", port.to_string())
         .spawn()
         .expect("failed to spawn doctorfill-server sidecar");
 
+    let pid = child.pid();
+
     // Windows: assign the sidecar PID to the Job Object immediately.
     // Any child processes it spawns (e.g. PyInstaller unpacked Python) will
     // also be part of the Job and killed when the Tauri process exits.
     #[cfg(windows)]
     {
-        let pid = child.pid();
         println!("[tauri] Sidecar PID: {}", pid);
         job::assign_pid_to_job(pid);
     }
 
+    // Unix: make the sidecar the leader of its own new process group, mirroring
+    // the Job Object on Windows. The PyInstaller bootstrapper and its unpacked
+    // Python child inherit the same pgid (a child only gets its own group if it
+    // calls setpgid/setsid itself), so `killpg` on this pgid reaps the whole
+    // tree in one signal.
+    //
+    // Caveat: this `setpgid` call happens in the parent, after `spawn()`
+    // returns, not atomically before the child starts running. `exec` never
+    // changes a process's pgid, but `fork` fixes a child's pgid at the moment
+    // it forks — so if the PyInstaller bootstrap forks its unpacked Python
+    // child before this line runs, that grandchild is stamped with our
+    // *original* pgid and never joins the new group, and `killpg` below won't
+    // reach it. `tauri_plugin_shell`'s sidecar spawn doesn't expose a
+    // `pre_exec` hook, which is what would be needed to close this race
+    // properly (set the pgid in the child, between fork and exec). In
+    // practice the bootstrap's first fork is slow enough relative to this
+    // call that the race hasn't been observed, but it is real.
+    #[cfg(unix)]
+    {
+        let pgid = pid as libc::pid_t;
+        unsafe {
+            if libc::setpgid(pgid, pgid) != 0 {
+                eprintln!("[tauri] setpgid failed for sidecar PID {}", pid);
+            }
+        }
+    }
+
+    if let Some(state) = app.try_state::<Sidecar>() {
+        state.replace(child);
+        state.set_attempt(attempt);
+    } else {
+        app.manage(Sidecar::new(child, port));
+    }
+
+    let (died_tx, died) = tokio::sync::watch::channel(false);
+
+    #[cfg(target_os = "linux")]
+    watch_pidfd(pid, died_tx.clone());
+
+    let watch_app = app.clone();
     tauri::async_runtime::spawn(async move {
         while let Some(event) = rx.recv().await {
             match event {
@@ -216,14 +505,247 @@ fn start_python_backend(app: &tauri::AppHandle) -> tauri_plugin_shell::process::
                 }
                 CommandEvent::Terminated(payload) => {
                     println!("[python] terminated: {:?}", payload);
-                    break;
+                    let _ = died_tx.send(true);
+                    let state = watch_app.try_state::<Sidecar>();
+                    let shutting_down = state.as_deref().is_some_and(Sidecar::is_shutting_down);
+                    // A process that `kill_for_restart` already took out of the
+                    // state, or that a newer generation replaced, was stopped on
+                    // purpose and is not handed to the crash supervisor.
+                    let is_current = state.as_deref().is_some_and(|s| {
+                        s.process
+                            .lock()
+                            .ok()
+                            .and_then(|guard| guard.as_ref().map(|p| p.child.pid()))
+                            == Some(pid)
+                    });
+                    if !shutting_down && is_current {
+                        // Read the attempt count back from the managed state rather
+                        // than using the `attempt` this generation was spawned with:
+                        // if this generation went healthy before crashing again,
+                        // `health_check_and_navigate` will have reset it to 0, so a
+                        // fresh string of crashes gets the full restart budget.
+                        let current_attempt = state.as_deref().map_or(attempt, Sidecar::attempt);
+                        handle_unexpected_exit(watch_app, current_attempt);
+                    }
+                    return;
                 }
                 _ => {}
             }
         }
     });
 
-    child
+    died
+}
+
+/// Linux: race-free death detection via `pidfd_open`. A pidfd becomes
+/// readable exactly once, the moment the process exits — this lets us tell
+/// "still booting" apart from "already dead" immediately, rather than relying
+/// only on `CommandEvent::Terminated`/SIGCHLD plumbing through the shell
+/// plugin. Mirrors the approach `async-process`'s waitable-pidfd backend uses.
+/// Logs the failure and falls back to the `CommandEvent::Terminated` path (already wired
+/// into `died`) on kernels without pidfd support (pre-5.3) or if `AsyncFd`
+/// setup fails.
+#[cfg(target_os = "linux")]
+fn watch_pidfd(pid: u32, died_tx: tokio::sync::watch::Sender<bool>) {
+    struct PidFd(std::os::fd::RawFd);
+
+    impl std::os::fd::AsRawFd for PidFd {
+        fn as_raw_fd(&self) -> std::os::fd::RawFd {
+            self.0
+        }
+    }
+
+    impl Drop for PidFd {
+        fn drop(&mut self) {
+            unsafe {
+                libc::close(self.0);
+            }
+        }
+    }
+
+    let fd = unsafe { libc::syscall(libc::SYS_pidfd_open, pid as libc::pid_t, 0) };
+    if fd < 0 {
+        eprintln!("[tauri] pidfd_open failed, relying on CommandEvent::Terminated only");
+        return;
+    }
+    let pidfd = PidFd(fd as std::os::fd::RawFd);
+
+    tauri::async_runtime::spawn(async move {
+        let async_fd = match tokio::io::unix::AsyncFd::new(pidfd) {
+            Ok(async_fd) => async_fd,
+            Err(e) => {
+                eprintln!("[tauri] AsyncFd::new on pidfd failed: {}", e);
+                return;
+            }
+        };
+
+        if async_fd.readable().await.is_ok() {
+            println!("[tauri] pidfd reports sidecar exited");
+            let _ = died_tx.send(true);
+        }
+    });
+}
+
+/// Called when the sidecar's `CommandEvent::Terminated` fires while the app is
+/// not shutting down. Restarts it with exponential backoff, re-running the
+/// health-check-and-navigate sequence once it's back up; gives up and shows
+/// the error page after `MAX_RESTART_ATTEMPTS`.
+fn handle_unexpected_exit(app: tauri::AppHandle, attempt: u32) {
+    if attempt >= MAX_RESTART_ATTEMPTS {
+        eprintln!(
+            "[tauri] Sidecar still crashing after {} restart attempts, giving up",
+            attempt
+        );
+        let _ = app.emit(
+            "backend://failed",
+            serde_json::json!({ "reason": "crashed", "attempts": attempt }),
+        );
+        navigate_to_error_page(&app);
+        return;
+    }
+
+    let delay = RESTART_BACKOFF_BASE
+        .saturating_mul(1 << attempt)
+        .min(RESTART_BACKOFF_MAX);
+    eprintln!(
+        "[tauri] Sidecar crashed unexpectedly, restarting in {:?} (attempt {}/{})",
+        delay,
+        attempt + 1,
+        MAX_RESTART_ATTEMPTS
+    );
+
+    // Reuse the port picked on the very first spawn rather than choosing a new
+    // one on every restart.
+    let port = app
+        .try_state::<Sidecar>()
+        .map(|s| s.port())
+        .unwrap_or_else(pick_free_port);
+
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(delay).await;
+
+        if app
+            .try_state::<Sidecar>()
+            .is_some_and(|s| s.is_shutting_down())
+        {
+            return;
+        }
+
+        let died = spawn_and_watch(app.clone(), attempt + 1, port);
+        health_check_and_navigate(app, Some(died), port);
+    });
+}
+
+/// Awaits the next death notification, if there is one to watch. In dev mode
+/// there's no sidecar — `died` is `None` — and this returns at once, so the
+/// `tokio::select!` below guards its arm with `if died.is_some()`; with the arm
+/// disabled, every iteration goes to the health-check arm.
+async fn wait_for_death(died: &mut Option<DeathSignal>) {
+    if let Some(died) = died {
+        let _ = died.changed().await;
+    }
+}
+
+/// Poll the sidecar's `/health` endpoint and navigate the main window to it
+/// once it responds. Run once at startup and again after every crash restart,
+/// so a respawned backend gets the same warm-up treatment as the first one.
+/// Bails out immediately (instead of exhausting `max_retries`) if `died`
+/// fires, which happens the moment the sidecar is known to have exited.
+/// `died` is `None` in dev mode, where the Flask server runs outside Tauri
+/// and there's nothing to watch for.
+fn health_check_and_navigate(handle: tauri::AppHandle, mut died: Option<DeathSignal>, port: u16) {
+    tauri::async_runtime::spawn(async move {
+        let url = format!("http://localhost:{}/health", port);
+        let max_retries = 60;
+
+        for i in 1..=max_retries {
+            let _ = handle.emit(
+                "backend://health-attempt",
+                serde_json::json!({ "attempt": i, "max": max_retries }),
+            );
+
+            tokio::select! {
+                biased;
+                _ = wait_for_death(&mut died), if died.is_some() => {
+                    eprintln!("[tauri] Sidecar died during startup, aborting health check");
+                    let _ = handle.emit(
+                        "backend://failed",
+                        serde_json::json!({ "reason": "died_during_startup", "attempt": i }),
+                    );
+                    return;
+                }
+                result = reqwest::get(&url) => {
+                    match result {
+                        Ok(resp) if resp.status().is_success() => {
+                            println!("[tauri] Backend ready after {} attempt(s)", i);
+                            // This generation is healthy: clear the crash-restart
+                            // counter so a future incident gets the full budget
+                            // instead of picking up where a long-resolved one left off.
+                            if let Some(state) = handle.try_state::<Sidecar>() {
+                                state.reset_attempt();
+                            }
+                            let _ = handle.emit("backend://ready", ());
+                            if let Some(w) = handle.get_webview_window("main") {
+                                let flask_url: tauri::Url = format!("http://localhost:{}", port)
+                                    .parse()
+                                    .expect("invalid Flask URL");
+                                let _ = w.navigate(flask_url);
+                            }
+                            return;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            println!(
+                "[tauri] Waiting for backend... attempt {}/{}",
+                i, max_retries
+            );
+            tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+        }
+        eprintln!("[tauri] Backend did not start in time!");
+        let _ = handle.emit(
+            "backend://failed",
+            serde_json::json!({ "reason": "timeout", "attempts": max_retries }),
+        );
+    });
+}
+
+/// Show a plain error page in the main window in place of the loading screen
+/// or a stale backend response, used once the crash supervisor gives up.
+fn navigate_to_error_page(app: &tauri::AppHandle) {
+    let Some(window) = app.get_webview_window("main") else {
+        return;
+    };
+    let Ok(url) = error_html().parse::<tauri::Url>() else {
+        return;
+    };
+    let _ = window.navigate(url);
+}
+
+/// Invoked from the "Réessayer" button on the loading page after the initial
+/// health check gives up (`reason: "timeout"` or `"died_during_startup"`).
+/// Tears down whatever's left of the old sidecar, if any, and re-runs the
+/// spawn-and-health-check sequence from a clean `attempt` counter, same as
+/// the very first launch.
+#[tauri::command]
+fn retry_backend(app: tauri::AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        if let Ok(url) = loading_html().parse::<tauri::Url>() {
+            let _ = window.navigate(url);
+        }
+    }
+
+    let port = if let Some(state) = app.try_state::<Sidecar>() {
+        state.kill_for_restart();
+        state.port()
+    } else {
+        pick_free_port()
+    };
+
+    let died = spawn_and_watch(app.clone(), 0, port);
+    health_check_and_navigate(app, Some(died), port);
 }
 
 // ── Loading page ──────────────────────────────────────────────────────────────
@@ -253,14 +775,60 @@ fn loading_html() -> String {
   }
   @keyframes spin { to { transform:rotate(360deg); } }
   .loader p { opacity:0.8; font-size:0.9rem; }
+  .loader button {
+    margin-top:1rem; margin-right:0.5rem; padding:0.5rem 1rem; border:none; border-radius:4px;
+    background:rgba(255,255,255,0.9); cursor:pointer; font-size:0.85rem;
+  }
+  .loader .actions { display:none; }
+  .loader.failed .spinner { display:none; }
+  .loader.failed .actions { display:block; }
 </style>
 </head>
 <body>
-  <div class="loader">
+  <div class="loader" id="loader">
     <h1>DoctorFill</h1>
     <div class="spinner"></div>
-    <p>Démarrage du serveur...</p>
+    <p id="status">Démarrage du serveur...</p>
+    <div class="actions">
+      <button id="retry-btn">Réessayer</button>
+      <button onclick="window.__TAURI__.process.exit(1)">Quitter</button>
+    </div>
   </div>
+  <script>
+    (function () {
+      var statusEl = document.getElementById('status');
+      var loaderEl = document.getElementById('loader');
+      var retryBtn = document.getElementById('retry-btn');
+      var invoke = window.__TAURI__ && window.__TAURI__.core && window.__TAURI__.core.invoke;
+      if (retryBtn && invoke) {
+        retryBtn.addEventListener('click', function () {
+          loaderEl.classList.remove('failed');
+          statusEl.textContent = 'Nouvelle tentative...';
+          invoke('retry_backend');
+        });
+      }
+      var listen = window.__TAURI__ && window.__TAURI__.event && window.__TAURI__.event.listen;
+      if (!listen) return;
+      listen('backend://starting', function () {
+        statusEl.textContent = 'Démarrage du serveur...';
+      });
+      listen('backend://health-attempt', function (e) {
+        statusEl.textContent = 'En attente du serveur... (' + e.payload.attempt + '/' + e.payload.max + ')';
+      });
+      listen('backend://ready', function () {
+        statusEl.textContent = 'Serveur prêt.';
+      });
+      listen('backend://failed', function () {
+        statusEl.textContent = "Le serveur n'a pas pu démarrer.";
+        loaderEl.classList.add('failed');
+      });
+      listen('updater://progress', function (e) {
+        if (e.payload.total) {
+          statusEl.textContent = 'Téléchargement de la mise à jour... (' + e.payload.downloaded + '/' + e.payload.total + ')';
+        }
+      });
+    })();
+  </script>
 </body>
 </html>"#;
 
@@ -274,6 +842,42 @@ fn loading_html() -> String {
     b64
 }
 
+/// Shown in place of the loading page once the crash supervisor exhausts its
+/// restart attempts, so the user sees a clear failure instead of an endless
+/// spinner or stale content.
+fn error_html() -> String {
+    let html = r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<style>
+  body {
+    margin:0; min-height:100vh; display:flex; align-items:center; justify-content:center;
+    background:linear-gradient(135deg,#434343 0%,#000000 100%);
+    font-family:-apple-system,BlinkMacSystemFont,'Segoe UI',Roboto,sans-serif;
+  }
+  .error { text-align:center; color:white; max-width:28rem; padding:0 1.5rem; }
+  .error h1 { font-size:1.5rem; margin-bottom:1rem; }
+  .error p { opacity:0.8; font-size:0.9rem; }
+</style>
+</head>
+<body>
+  <div class="error">
+    <h1>DoctorFill n'a pas pu démarrer</h1>
+    <p>Le serveur backend s'est arrêté de façon inattendue et n'a pas pu redémarrer. Fermez puis relancez l'application.</p>
+  </div>
+</body>
+</html>"#;
+
+    let mut b64 = String::new();
+    {
+        use std::fmt::Write as FmtWrite;
+        let encoded = base64_encode(html.as_bytes());
+        let _ = write!(&mut b64, "data:text/html;base64,{}", encoded);
+    }
+    b64
+}
+
 /// Simple base64 encoder (no external crate needed).
 fn base64_encode(input: &[u8]) -> String {
     const CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
@@ -314,6 +918,7 @@ pub fn run() {
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_process::init())
         .plugin(tauri_plugin_updater::Builder::new().build())
+        .invoke_handler(tauri::generate_handler![retry_backend])
         .setup(move |app| {
             // ── Create the window immediately with a loading page ─────
             let loading_url: tauri::Url = loading_html().parse().expect("invalid data URI");
@@ -332,10 +937,16 @@ pub fn run() {
 
             // ── Start sidecar (production only) ──────────────────────
             #[cfg(not(dev))]
-            {
-                let child = start_python_backend(app.handle());
-                app.manage(Sidecar::new(child));
-            }
+            let port = pick_free_port();
+            #[cfg(dev)]
+            let port = DEV_FLASK_PORT;
+
+            #[cfg(not(dev))]
+            let died = Some(spawn_and_watch(app.handle().clone(), 0, port));
+            // In dev mode the Flask server is run separately, not spawned by
+            // us, so there's no sidecar death to watch for.
+            #[cfg(dev)]
+            let died = None;
 
             // ── Check for updates in background (production only) ────
             // Silent check: downloads and installs automatically, then
@@ -354,9 +965,10 @@ pub fn run() {
                                         update.version
                                     );
                                     let mut downloaded = 0u64;
+                                    let progress_handle = update_handle.clone();
                                     match update
                                         .download_and_install(
-                                            |chunk, total| {
+                                            move |chunk, total| {
                                                 downloaded += chunk as u64;
                                                 if let Some(t) = total {
                                                     println!(
@@ -364,6 +976,13 @@ pub fn run() {
                                                         downloaded, t
                                                     );
                                                 }
+                                                let _ = progress_handle.emit(
+                                                    "updater://progress",
+                                                    serde_json::json!({
+                                                        "downloaded": downloaded,
+                                                        "total": total,
+                                                    }),
+                                                );
                                             },
                                             || {
                                                 println!("[tauri] Update installed, restart required.");
@@ -390,35 +1009,7 @@ pub fn run() {
             }
 
             // ── Navigate to Flask once ready (async, non-blocking) ───
-            let handle = app.handle().clone();
-            tauri::async_runtime::spawn(async move {
-                let url = format!("http://localhost:{}/health", FLASK_PORT);
-                let max_retries = 60;
-
-                for i in 1..=max_retries {
-                    match reqwest::get(&url).await {
-                        Ok(resp) if resp.status().is_success() => {
-                            println!("[tauri] Backend ready after {} attempt(s)", i);
-                            if let Some(w) = handle.get_webview_window("main") {
-                                let flask_url: tauri::Url =
-                                    format!("http://localhost:{}", FLASK_PORT)
-                                        .parse()
-                                        .expect("invalid Flask URL");
-                                let _ = w.navigate(flask_url);
-                            }
-                            return;
-                        }
-                        _ => {
-                            println!(
-                                "[tauri] Waiting for backend... attempt {}/{}",
-                                i, max_retries
-                            );
-                            tokio::time::sleep(std::time::Duration::from_millis(500)).await;
-                        }
-                    }
-                }
-                eprintln!("[tauri] Backend did not start in time!");
-            });
+            health_check_and_navigate(app.handle().clone(), died, port);
 
             Ok(())
         })
@@ -432,13 +1023,10 @@ pub fn run() {
                     println!("[tauri] App exiting, cleaning up sidecar...");
                     if let Some(state) = app_handle.try_state::<Sidecar>() {
                         state.kill();
-                    } else {
-                        // Fallback if state was never registered (dev mode).
-                        // On Windows the Job Object handles cleanup automatically.
-                        // On Unix, fall back to port-based kill.
-                        #[cfg(unix)]
-                        Sidecar::kill_port_unix();
                     }
+                    // If state was never registered (dev mode), there's no
+                    // Rust-managed sidecar to clean up: the Flask server is run
+                    // separately by the dev workflow, not spawned by us.
                 }
                 _ => {}
             }